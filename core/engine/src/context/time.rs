@@ -6,20 +6,40 @@
 //! * Any deep code can then obtain a strictly‑monotonic instant via
 //!   [`StdClock::now`].  Each successive `now()` within the same entry‑point
 //!   is guaranteed to be **≥** the previous one (base time + bump [ns]).
+//! * Code that wants the raw block time instead (e.g. `Date.now()`, which may
+//!   repeat across calls) should use [`wall_now`] / [`WallClock`] rather than
+//!   `StdClock`.
 //! * Call [`clear_time`] before returning from the entry‑point to avoid
 //!   accidental leakage when the VM reuses the same Wasm instance.
 //!
-//! Public surface (structs / traits) remains unchanged, ensuring full
-//! compatibility for upstream code, but all host‑clock and threading
-//! dependencies are removed.
+//! `JsInstant`/`JsDuration` keep their original shape, but [`Clock`] is no
+//! longer a drop‑in replacement for any prior version: it now exposes an
+//! associated `type Instant: Reference` instead of hard‑coding `JsInstant`
+//! as `now`'s return type, so implementors written against an older
+//! single‑type `Clock` need to add `type Instant = JsInstant;`. All
+//! host‑clock and threading dependencies are removed either way.
 
 #![allow(clippy::missing_inline_in_public_items)]
 
-use core::{cell::Cell, time::Duration};
-use monotonic_time::next_duration;
+extern crate alloc;
+
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::time::Duration;
+use monotonic_time::{next_duration, wall_duration};
 
 pub use monotonic_time::{clear_time, set_time_nanos};
 
+/// Returns the raw block time (no monotonic `+1ns` bump applied).
+///
+/// `Date.now()` wants this: the *wall* time, which may repeat across calls
+/// within the same entry‑point, or even move backwards relative to a
+/// previous entry‑point. Code that needs a strictly‑increasing instant
+/// (`performance.now()`, timers) should use [`StdClock::now`] instead.
+#[must_use]
+pub fn wall_now() -> JsInstant {
+    JsInstant::new_unchecked(wall_duration())
+}
+
 /*────────────────────────────  JsInstant  ────────────────────────────────*/
 
 /// A monotonic instant in time, in the Boa engine (nanosecond resolution).
@@ -54,6 +74,147 @@ impl JsInstant {
     pub fn nanos_since_epoch(&self) -> u128 {
         self.inner.as_nanos()
     }
+
+    /// Returns `Some(self + duration)`, or `None` if the result would
+    /// overflow the underlying [`Duration`].
+    #[must_use]
+    pub fn checked_add(self, duration: JsDuration) -> Option<Self> {
+        self.inner
+            .checked_add(duration.inner)
+            .map(Self::new_unchecked)
+    }
+
+    /// Returns `Some(self - duration)`, or `None` if the result would be
+    /// negative.
+    #[must_use]
+    pub fn checked_sub(self, duration: JsDuration) -> Option<Self> {
+        self.inner
+            .checked_sub(duration.inner)
+            .map(Self::new_unchecked)
+    }
+
+    /// Returns `self + duration`, saturating at the maximum representable
+    /// instant instead of overflowing.
+    #[must_use]
+    pub fn saturating_add(self, duration: JsDuration) -> Self {
+        Self::new_unchecked(self.inner.saturating_add(duration.inner))
+    }
+
+    /// Returns `self - duration`, saturating at the epoch instead of going
+    /// negative.
+    #[must_use]
+    pub fn saturating_sub(self, duration: JsDuration) -> Self {
+        Self::new_unchecked(self.inner.saturating_sub(duration.inner))
+    }
+
+    /// Returns `Some(self - earlier)`, or `None` if `self` is before
+    /// `earlier`.
+    #[must_use]
+    pub fn checked_duration_since(self, earlier: Self) -> Option<JsDuration> {
+        self.inner
+            .checked_sub(earlier.inner)
+            .map(JsDuration::from)
+    }
+
+    /// Returns `self - earlier`, or a zero [`JsDuration`] if `self` is
+    /// before `earlier`, instead of panicking.
+    #[must_use]
+    pub fn saturating_duration_since(self, earlier: Self) -> JsDuration {
+        JsDuration::from(self.inner.saturating_sub(earlier.inner))
+    }
+
+    /// Breaks this instant down into UTC calendar fields, for the `Date`
+    /// getters (`getUTCFullYear`, `getUTCMonth`, …).
+    ///
+    /// Uses Howard Hinnant's "days from civil" algorithm in pure integer
+    /// arithmetic, so it stays deterministic and allocation‑free without
+    /// `chrono` or `std::time::SystemTime`. `CivilDateTime` only resolves to
+    /// millisecond precision, so any nanoseconds below 1ms are truncated
+    /// here and lost — `to_civil().`[`from_civil`](JsInstant::from_civil)`()`
+    /// only round‑trips exactly for instants that already sit on a
+    /// millisecond boundary.
+    #[must_use]
+    pub fn to_civil(&self) -> CivilDateTime {
+        let millis_since_epoch = self.inner.as_millis() as i64;
+        let days = millis_since_epoch.div_euclid(86_400_000);
+        let ms_of_day = millis_since_epoch.rem_euclid(86_400_000);
+
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097; // [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+        let mp = (5 * doy + 2) / 153; // [0, 11]
+        let d = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+        let m = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+        let y = y + i64::from(m <= 2);
+
+        let hour = ms_of_day / 3_600_000;
+        let minute = (ms_of_day / 60_000) % 60;
+        let second = (ms_of_day / 1_000) % 60;
+        let millisecond = ms_of_day % 1_000;
+
+        CivilDateTime {
+            year: y,
+            month: m as u8,
+            day: d as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            millisecond: millisecond as u16,
+        }
+    }
+
+    /// Inverse of [`JsInstant::to_civil`]: builds an instant from UTC
+    /// calendar fields using the same days‑from‑civil algorithm run forward.
+    /// Millisecond precision only — see [`JsInstant::to_civil`] for the
+    /// round‑trip caveat on sub‑millisecond instants.
+    ///
+    /// `JsInstant` is `Duration`‑backed and so cannot represent an instant
+    /// before the Unix epoch: a `civil` before 1970‑01‑01T00:00:00Z is
+    /// clamped to [`JsInstant::new(0, 0)`](JsInstant::new) rather than
+    /// silently wrapping into a nonsense far‑future instant.
+    #[must_use]
+    pub fn from_civil(civil: CivilDateTime) -> Self {
+        let y = civil.year - i64::from(civil.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400; // [0, 399]
+        let m = i64::from(civil.month);
+        let d = i64::from(civil.day);
+        let mp = if m > 2 { m - 3 } else { m + 9 }; // [0, 11]
+        let doy = (153 * mp + 2) / 5 + d - 1; // [0, 365]
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+        let days = era * 146_097 + doe - 719_468;
+
+        let ms_of_day = i64::from(civil.hour) * 3_600_000
+            + i64::from(civil.minute) * 60_000
+            + i64::from(civil.second) * 1_000
+            + i64::from(civil.millisecond);
+        let millis_since_epoch = (days * 86_400_000 + ms_of_day).max(0);
+
+        Self::new_unchecked(Duration::from_millis(millis_since_epoch as u64))
+    }
+}
+
+/// UTC calendar fields derived from a [`JsInstant`], for implementing JS
+/// `Date` getters without `chrono`/`std`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CivilDateTime {
+    /// Proleptic Gregorian year (may be negative for dates before year 0).
+    pub year: i64,
+    /// Month, 1-12.
+    pub month: u8,
+    /// Day of month, 1-31.
+    pub day: u8,
+    /// Hour of day, 0-23.
+    pub hour: u8,
+    /// Minute of hour, 0-59.
+    pub minute: u8,
+    /// Second of minute, 0-59.
+    pub second: u8,
+    /// Millisecond of second, 0-999.
+    pub millisecond: u16,
 }
 
 /*────────────────────────────  JsDuration  ───────────────────────────────*/
@@ -66,7 +227,7 @@ pub struct JsDuration {
 impl JsDuration {
     /// Creates a new `JsDuration` from the given number of milliseconds.
     #[must_use]
-    pub fn from_millis(millis: u64) -> Self {
+    pub const fn from_millis(millis: u64) -> Self {
         Self {
             inner: Duration::from_millis(millis),
         }
@@ -84,6 +245,31 @@ impl JsDuration {
     pub fn as_nanos(&self) -> u128 {
         self.inner.as_nanos()
     }
+
+    /// Returns `Some(self + rhs)`, or `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.inner.checked_add(rhs.inner).map(Self::from)
+    }
+
+    /// Returns `Some(self - rhs)`, or `None` if `rhs` is larger than `self`.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.inner.checked_sub(rhs.inner).map(Self::from)
+    }
+
+    /// Returns `self + rhs`, saturating at the maximum representable
+    /// duration instead of overflowing.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::from(self.inner.saturating_add(rhs.inner))
+    }
+
+    /// Returns `self - rhs`, saturating at zero instead of underflowing.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from(self.inner.saturating_sub(rhs.inner))
+    }
 }
 
 impl From<Duration> for JsDuration {
@@ -131,21 +317,73 @@ impl core::ops::Sub for JsInstant {
     }
 }
 
+/*────────────────────────────  Reference  ────────────────────────────────*/
+
+/// A point in time usable as a [`Clock::Instant`].
+///
+/// Modeled on governor's rate‑limiter clock abstraction: it is the minimal
+/// surface a token‑bucket limiter or scheduler needs from an instant type,
+/// without tying the trait to `JsInstant` specifically, so mocks or cheaper
+/// counters can stand in for it.
+pub trait Reference: Sized + Copy + Ord + core::ops::Add<JsDuration, Output = Self> {
+    /// Returns the duration elapsed between `earlier` and `self`, saturating
+    /// to zero instead of panicking if `self` is before `earlier`.
+    #[must_use]
+    fn duration_since(&self, earlier: Self) -> JsDuration;
+
+    /// Returns `self - duration`, saturating at the earliest representable
+    /// instant instead of underflowing.
+    #[must_use]
+    fn saturating_sub(&self, duration: JsDuration) -> Self;
+}
+
+impl Reference for JsInstant {
+    fn duration_since(&self, earlier: Self) -> JsDuration {
+        self.saturating_duration_since(earlier)
+    }
+
+    fn saturating_sub(&self, duration: JsDuration) -> Self {
+        JsInstant::saturating_sub(*self, duration)
+    }
+}
+
 /*──────────────────────────────  Clock  ─────────────────────────────────*/
 
 pub trait Clock {
-    fn now(&self) -> JsInstant;
+    /// The kind of instant this clock produces. Generic limiters and
+    /// schedulers can be written against `Clock` without committing to
+    /// `JsInstant` specifically.
+    type Instant: Reference;
+
+    fn now(&self) -> Self::Instant;
 }
 
 /// `StdClock` now reads from the deterministic global time slot.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct StdClock;
 impl Clock for StdClock {
+    type Instant = JsInstant;
+
     fn now(&self) -> JsInstant {
         JsInstant::new_unchecked(next_duration())
     }
 }
 
+/// `WallClock` reads the raw block time, with no monotonic bump.
+///
+/// Use this for `Date.now()`‑style semantics, where repeated reads within
+/// one entry‑point should return the *same* instant. For a strictly
+/// monotonic instant (`performance.now()`, timers), use [`StdClock`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WallClock;
+impl Clock for WallClock {
+    type Instant = JsInstant;
+
+    fn now(&self) -> JsInstant {
+        wall_now()
+    }
+}
+
 /// A fixed‑time clock, useful for unit tests.
 #[derive(Debug, Clone, Default)]
 pub struct FixedClock(core::cell::RefCell<u64>);
@@ -159,6 +397,8 @@ impl FixedClock {
     }
 }
 impl Clock for FixedClock {
+    type Instant = JsInstant;
+
     fn now(&self) -> JsInstant {
         let millis = *self.0.borrow();
         JsInstant::new_unchecked(Duration::new(
@@ -168,6 +408,209 @@ impl Clock for FixedClock {
     }
 }
 
+/*───────────────────────────  TimerQueue  ────────────────────────────────*/
+
+/// Smallest period a periodic timer can re‑arm with. Anything shorter
+/// (including zero, from `setInterval(fn, 0)`) is clamped up to this so a
+/// re‑armed deadline is always guaranteed to move past "now" in a bounded
+/// number of steps.
+const MIN_PERIODIC_TICK: JsDuration = JsDuration::from_millis(1);
+
+/// Identifier handed back by [`TimerQueue::schedule`]/[`TimerQueue::schedule_periodic`],
+/// used to [`TimerQueue::cancel`] a pending timer.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct TimerId(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimerEntry {
+    deadline: JsInstant,
+    id: TimerId,
+    period: Option<JsDuration>,
+}
+
+// `BinaryHeap` is a max-heap; order entries by *earliest* deadline first by
+// reversing the comparison (ties broken by id so expiry order is stable).
+impl Ord for TimerEntry {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        other
+            .deadline
+            .cmp(&self.deadline)
+            .then_with(|| other.id.0.cmp(&self.id.0))
+    }
+}
+impl PartialOrd for TimerEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A deterministic `setTimeout`/`setInterval` scheduler.
+///
+/// With a block‑deterministic clock there is no real sleeping: timers fire
+/// purely based on the block time published via [`set_time_nanos`] and the
+/// monotonic bump from [`StdClock`]. An entry‑point drains due timers by
+/// calling [`TimerQueue::advance_to`] with the current instant and then
+/// draining [`TimerQueue::poll_expired`], mirroring embassy‑time's
+/// host‑driven timer‑queue model rather than relying on OS threads.
+///
+/// Built on `alloc::collections::BinaryHeap`, like the rest of this module's
+/// `core`/`alloc`‑only surface (no `std::collections`). The engine itself
+/// has no `#![no_std]` build: the clock source comes from `monotonic_time`,
+/// which uses `std`.
+#[derive(Debug)]
+pub struct TimerQueue {
+    heap: BinaryHeap<TimerEntry>,
+    next_id: u64,
+    /// Seeded at the Unix epoch so `schedule`/`schedule_periodic` can be
+    /// called right after [`TimerQueue::new`], before any
+    /// [`TimerQueue::advance_to`]/[`TimerQueue::poll_expired`] — scheduling
+    /// must never panic on a freshly‑created queue.
+    now: JsInstant,
+}
+
+impl Default for TimerQueue {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            next_id: 0,
+            now: JsInstant::new(0, 0),
+        }
+    }
+}
+
+impl TimerQueue {
+    /// Creates an empty timer queue, with "now" seeded at the Unix epoch
+    /// until the first [`TimerQueue::advance_to`]/[`TimerQueue::poll_expired`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next_id(&mut self) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+
+    /// Schedules a one‑shot timer (`setTimeout`) to fire `delay` after the
+    /// last instant passed to [`TimerQueue::advance_to`] (the Unix epoch, if
+    /// none yet).
+    pub fn schedule(&mut self, delay: JsDuration) -> TimerId {
+        let deadline = self.now.saturating_add(delay);
+        let id = self.next_id();
+        self.heap.push(TimerEntry {
+            deadline,
+            id,
+            period: None,
+        });
+        id
+    }
+
+    /// Schedules a recurring timer (`setInterval`) whose first deadline is
+    /// `period` after the last instant passed to [`TimerQueue::advance_to`]
+    /// (the Unix epoch, if none yet), and which re‑arms itself for `period`
+    /// again every time it fires.
+    ///
+    /// A `period` of zero (the ubiquitous `setInterval(fn, 0)`) is clamped up
+    /// to [`MIN_PERIODIC_TICK`] so the timer cannot re‑arm with a deadline
+    /// that never moves past "now", which would spin [`TimerQueue::poll_expired`]
+    /// forever.
+    pub fn schedule_periodic(&mut self, period: JsDuration) -> TimerId {
+        let period = period.max(MIN_PERIODIC_TICK);
+        let deadline = self.now.saturating_add(period);
+        let id = self.next_id();
+        self.heap.push(TimerEntry {
+            deadline,
+            id,
+            period: Some(period),
+        });
+        id
+    }
+
+    /// Cancels a pending timer, removing it from the queue outright.
+    ///
+    /// Cancelling an id that already fired (a one‑shot) or was never
+    /// scheduled is a silent no‑op, matching `clearTimeout`/`clearInterval`
+    /// semantics. Pruning the entry here — rather than recording the id and
+    /// leaving it in the heap for [`TimerQueue::poll_expired`] to skip over
+    /// later — matters because a timer whose deadline the block clock never
+    /// reaches would otherwise sit in the queue forever, which is unbounded
+    /// growth for a `TimerQueue` reused across many entry‑points.
+    pub fn cancel(&mut self, id: TimerId) {
+        self.heap = core::mem::take(&mut self.heap)
+            .into_iter()
+            .filter(|entry| entry.id != id)
+            .collect();
+    }
+
+    /// Returns the number of timers still pending (scheduled but neither
+    /// fired nor cancelled).
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Advances the queue's notion of "now" without draining it, for
+    /// host‑driven stepping between calls to [`TimerQueue::poll_expired`].
+    pub fn advance_to(&mut self, instant: JsInstant) {
+        self.now = instant;
+    }
+
+    /// Advances to `clock.now()` and returns every timer id whose deadline
+    /// is `<= clock.now()`, in deadline order.
+    ///
+    /// Periodic timers are re‑inserted with a deadline strictly after `now`:
+    /// if one or more full periods elapsed since the last fire (normal for a
+    /// per‑block clock, where `now` can jump far ahead between polls), the
+    /// missed ticks are coalesced into the single id returned by this poll
+    /// rather than replayed as a duplicate burst, mirroring how browsers
+    /// coalesce a backlogged `setInterval`.
+    ///
+    /// Returns an owned `Vec` rather than a lazy iterator: re‑arming a
+    /// periodic timer mutates `self.heap` as part of producing each item,
+    /// so a borrowing iterator over `&mut self` would need to either hold
+    /// the mutable borrow for the iterator's lifetime (blocking any other
+    /// use of the queue until it's dropped) or re‑implement `Iterator`
+    /// manually for no real benefit — draining eagerly into a `Vec` is both
+    /// simpler and what every call site needs anyway.
+    pub fn poll_expired(&mut self, clock: &impl Clock<Instant = JsInstant>) -> Vec<TimerId> {
+        self.advance_to(clock.now());
+        let now = self.now;
+        let mut expired = Vec::new();
+        while let Some(entry) = self.heap.peek() {
+            if entry.deadline > now {
+                break;
+            }
+            let entry = self.heap.pop().expect("just peeked Some");
+            if let Some(period) = entry.period {
+                self.heap.push(TimerEntry {
+                    deadline: Self::next_deadline_after(entry.deadline, period, now),
+                    id: entry.id,
+                    period: Some(period),
+                });
+            }
+            expired.push(entry.id);
+        }
+        expired
+    }
+
+    /// Returns the smallest `deadline + n * period` (`n >= 1`) that is
+    /// strictly greater than `now`, skipping over any ticks missed while
+    /// time was advancing (e.g. between blocks) instead of looping once per
+    /// missed tick. `period` is assumed to already be clamped to at least
+    /// [`MIN_PERIODIC_TICK`], which bounds this to a single division.
+    fn next_deadline_after(deadline: JsInstant, period: JsDuration, now: JsInstant) -> JsInstant {
+        let period_nanos = period.as_nanos().max(1);
+        let elapsed_nanos = now.saturating_duration_since(deadline).as_nanos();
+        let periods_to_skip = elapsed_nanos / period_nanos;
+        let advance_nanos = (periods_to_skip + 1).saturating_mul(period_nanos);
+        let next_nanos = deadline.nanos_since_epoch().saturating_add(advance_nanos);
+        let secs = u64::try_from(next_nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        let nanos = (next_nanos % 1_000_000_000) as u32;
+        JsInstant::new(secs, nanos)
+    }
+}
+
 /*──────────────────────────────  tests  ─────────────────────────────────*/
 
 #[cfg(test)]
@@ -186,4 +629,171 @@ mod tests {
         assert_eq!(b.nanos_since_epoch() - a.nanos_since_epoch(), 1);
         clear_time();
     }
+
+    #[test]
+    fn checked_sub_instant_underflow() {
+        let earlier = JsInstant::new(10, 0);
+        let later = JsInstant::new(20, 0);
+        assert_eq!(earlier.checked_sub(JsDuration::from_millis(20_000)), None);
+        assert_eq!(
+            earlier.checked_duration_since(later),
+            None,
+            "earlier is before later, so there is no non-negative duration between them"
+        );
+    }
+
+    #[test]
+    fn saturating_duration_since_never_panics() {
+        let earlier = JsInstant::new(10, 0);
+        let later = JsInstant::new(20, 0);
+        assert_eq!(
+            earlier.saturating_duration_since(later),
+            JsDuration::from_millis(0)
+        );
+        assert_eq!(
+            later.saturating_duration_since(earlier),
+            JsDuration::from_millis(10_000)
+        );
+    }
+
+    #[test]
+    fn saturating_add_duration_caps_at_max() {
+        let max = JsDuration::from(Duration::MAX);
+        assert_eq!(max.saturating_add(JsDuration::from_millis(1)), max);
+    }
+
+    #[test]
+    fn to_civil_known_epoch() {
+        // 2024-01-15T12:34:56.789Z
+        let instant = JsInstant::new(1_705_322_096, 789_000_000);
+        let civil = instant.to_civil();
+        assert_eq!(civil.year, 2024);
+        assert_eq!(civil.month, 1);
+        assert_eq!(civil.day, 15);
+        assert_eq!(civil.hour, 12);
+        assert_eq!(civil.minute, 34);
+        assert_eq!(civil.second, 56);
+        assert_eq!(civil.millisecond, 789);
+    }
+
+    #[test]
+    fn civil_round_trip() {
+        let instant = JsInstant::new(1_705_322_096, 789_000_000);
+        let civil = instant.to_civil();
+        assert_eq!(JsInstant::from_civil(civil), instant);
+    }
+
+    #[test]
+    fn from_civil_pre_epoch_clamps_to_zero() {
+        let pre_epoch = CivilDateTime {
+            year: 1969,
+            month: 12,
+            day: 31,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+        };
+        assert_eq!(JsInstant::from_civil(pre_epoch), JsInstant::new(0, 0));
+    }
+
+    #[test]
+    fn timer_queue_fires_in_deadline_order() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        let late = timers.schedule(JsDuration::from_millis(200));
+        let early = timers.schedule(JsDuration::from_millis(100));
+
+        assert!(timers.poll_expired(&clock).is_empty());
+
+        clock.forward(100);
+        assert_eq!(timers.poll_expired(&clock), vec![early]);
+
+        clock.forward(100);
+        assert_eq!(timers.poll_expired(&clock), vec![late]);
+    }
+
+    #[test]
+    fn timer_queue_reschedules_periodic_timers() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        let id = timers.schedule_periodic(JsDuration::from_millis(100));
+
+        clock.forward(100);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+
+        clock.forward(100);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+    }
+
+    #[test]
+    fn timer_queue_zero_period_does_not_spin_forever() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        let id = timers.schedule_periodic(JsDuration::from_millis(0));
+
+        // Clamped to `MIN_PERIODIC_TICK`, so it fires once per poll, not an
+        // infinite loop, and the re-armed deadline always moves forward.
+        clock.forward(1);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+        clock.forward(1);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+    }
+
+    #[test]
+    fn timer_queue_coalesces_missed_periodic_ticks() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        let id = timers.schedule_periodic(JsDuration::from_millis(100));
+
+        // Block time jumps far past several missed periods in one go; the
+        // backlog should coalesce into a single fire, not a burst of 9 ids.
+        clock.forward(950);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+
+        // And the timer must not immediately re-fire on the very next poll
+        // at the same instant: its new deadline is strictly after `now`.
+        assert!(timers.poll_expired(&clock).is_empty());
+    }
+
+    #[test]
+    fn timer_queue_cancel_drops_pending_timer() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        let id = timers.schedule(JsDuration::from_millis(100));
+        timers.cancel(id);
+
+        clock.forward(100);
+        assert!(timers.poll_expired(&clock).is_empty());
+    }
+
+    #[test]
+    fn timer_queue_schedule_before_advance_to_does_not_panic() {
+        // `TimerQueue::new()` followed directly by `schedule` is a valid,
+        // expected sequence and must not panic.
+        let mut timers = TimerQueue::new();
+        let id = timers.schedule(JsDuration::from_millis(100));
+
+        let clock = FixedClock::from_millis(100);
+        assert_eq!(timers.poll_expired(&clock), vec![id]);
+    }
+
+    #[test]
+    fn timer_queue_cancel_prunes_a_never_reached_timer() {
+        let clock = FixedClock::from_millis(0);
+        let mut timers = TimerQueue::new();
+        timers.advance_to(clock.now());
+        // A timer the block clock will never reach would otherwise sit in
+        // the heap forever; cancelling it must remove it outright so the
+        // queue does not grow unbounded across entry-points.
+        let id = timers.schedule(JsDuration::from_millis(u64::MAX));
+        timers.cancel(id);
+
+        assert_eq!(timers.pending_count(), 0);
+    }
 }
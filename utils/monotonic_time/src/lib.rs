@@ -15,6 +15,12 @@ unsafe impl<T> Sync for Global<T> {}
 
 static GLOBAL_TIME_NS: Global<Option<u128>> = Global(Cell::new(None));
 static GLOBAL_BUMP: Global<u64> = Global(Cell::new(0));
+/// Last nanosecond instant ever handed out by [`next_duration`]. Unlike
+/// `GLOBAL_TIME_NS`/`GLOBAL_BUMP`, this is **not** reset by `set_time_nanos`
+/// or `clear_time`: it is the watermark that keeps `now()` strictly
+/// monotonic across entry‑points, even when the published block time repeats
+/// or moves backwards on a reused Wasm instance.
+static GLOBAL_LAST_NS: Global<u128> = Global(Cell::new(0));
 
 /// Publish the current block‑time (nanoseconds since epoch).
 #[inline]
@@ -30,7 +36,28 @@ pub fn clear_time() {
     GLOBAL_BUMP.0.set(0);
 }
 
+/// Internal: return the raw block time as a [`Duration`], with **no**
+/// monotonic bump applied. Unlike [`next_duration`], repeated calls within
+/// the same entry‑point return the same value, matching the "wall" half of
+/// the wasmtime clocks split.
+#[inline]
+pub fn wall_duration() -> Duration {
+    let base = GLOBAL_TIME_NS
+        .0
+        .get()
+        .expect("GLOBAL_TIME_NS not initialised – call set_time_nanos() first");
+    let secs = (base / 1_000_000_000) as u64;
+    let nanos = (base % 1_000_000_000) as u32;
+    Duration::new(secs, nanos)
+}
+
 /// Internal: return a [`Duration`] representing the next monotone instant.
+///
+/// The candidate instant (block time + in‑entry‑point bump) is clamped
+/// against [`GLOBAL_LAST_NS`] \(the `std::time::monotonic::monotonize` trick\)
+/// so the value returned here is always strictly greater than every value
+/// ever returned before it, regardless of how `set_time_nanos`/`clear_time`
+/// were called in between.
 #[inline]
 pub fn next_duration() -> Duration {
     let base = GLOBAL_TIME_NS
@@ -40,8 +67,11 @@ pub fn next_duration() -> Duration {
     let bump = GLOBAL_BUMP.0.get();
     GLOBAL_BUMP.0.set(bump.wrapping_add(1));
 
-    let total = base + bump as u128; // total nanoseconds since epoch
-    let secs = (total / 1_000_000_000) as u64;
-    let nanos = (total % 1_000_000_000) as u32;
+    let candidate = base + bump as u128; // total nanoseconds since epoch
+    let emitted = candidate.max(GLOBAL_LAST_NS.0.get().wrapping_add(1));
+    GLOBAL_LAST_NS.0.set(emitted);
+
+    let secs = (emitted / 1_000_000_000) as u64;
+    let nanos = (emitted % 1_000_000_000) as u32;
     Duration::new(secs, nanos)
 }